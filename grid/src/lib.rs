@@ -41,51 +41,39 @@ impl Location {
 /// An XxY grid of T
 ///
 /// 0,0 is the upper left and projects rightward and down as coordinates advance.
+///
+/// Internally this is a single row-major `Vec<T>` (index `y*width+x`) rather
+/// than a `Vec<Vec<T>>`, which keeps iteration and neighbor lookups cache
+/// friendly. See `get_index`/`location_to_index` for working with the
+/// backing storage directly.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub struct Grid<T: Default + Clone> {
-    g: Vec<Vec<T>>,
+pub struct Grid<T: Clone> {
+    g: Vec<T>,
     width: usize,
     height: usize,
 }
 
 /// GridIter is the iterator for Grid
-pub struct GridIter<'a, T: Default + Clone> {
+pub struct GridIter<'a, T: Clone> {
     grid: &'a Grid<T>,
-    cur: Option<Location>,
+    cur: usize,
 }
 
-impl<'a, T: Default + Clone> Iterator for GridIter<'a, T> {
+impl<'a, T: Clone> Iterator for GridIter<'a, T> {
     type Item = (Location, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let new = match self.cur.clone() {
-            Some(mut c) => {
-                c.0 += 1;
-                if c.0 >= self.grid.width.try_into().unwrap() {
-                    c.0 = 0;
-                    c.1 += 1;
-                }
-                if c.1 >= self.grid.height.try_into().unwrap() {
-                    None
-                } else {
-                    Some(c)
-                }
-            }
-            None => Some(Location(0, 0)),
-        };
-        self.cur = new;
-        if self.cur.is_none() {
-            None
-        } else {
-            Some((
-                self.cur.as_ref().unwrap().clone(),
-                self.grid.get(self.cur.as_ref().unwrap()),
-            ))
+        if self.cur >= self.grid.g.len() {
+            return None;
         }
+        let loc = self.grid.index_to_location(self.cur);
+        let item = (loc, self.grid.get_index(self.cur));
+        self.cur += 1;
+        Some(item)
     }
 }
 
-impl<'a, T: Default + Clone> IntoIterator for &'a Grid<T> {
+impl<'a, T: Clone> IntoIterator for &'a Grid<T> {
     type Item = (Location, &'a T);
     type IntoIter = GridIter<'a, T>;
 
@@ -94,11 +82,188 @@ impl<'a, T: Default + Clone> IntoIterator for &'a Grid<T> {
     }
 }
 
-impl<'a, T: Default + Clone> Grid<T> {
+/// GridIterMut is the mutable iterator for Grid
+pub struct GridIterMut<'a, T: Clone> {
+    width: usize,
+    cur: usize,
+    iter: std::slice::IterMut<'a, T>,
+}
+
+impl<'a, T: Clone> Iterator for GridIterMut<'a, T> {
+    type Item = (Location, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let t = self.iter.next()?;
+        let loc = Location((self.cur % self.width) as isize, (self.cur / self.width) as isize);
+        self.cur += 1;
+        Some((loc, t))
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a mut Grid<T> {
+    type Item = (Location, &'a mut T);
+    type IntoIter = GridIterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// ColIter is an `ExactSizeIterator + DoubleEndedIterator` over a single
+/// column of a Grid, top to bottom.
+pub struct ColIter<'a, T: Clone> {
+    grid: &'a Grid<T>,
+    x: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: Clone> Iterator for ColIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let idx = self.front * self.grid.width + self.x;
+        self.front += 1;
+        Some(self.grid.get_index(idx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for ColIter<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for ColIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let idx = self.back * self.grid.width + self.x;
+        Some(self.grid.get_index(idx))
+    }
+}
+
+/// IterRows is an `ExactSizeIterator + DoubleEndedIterator` over the rows of
+/// a Grid, each itself an `ExactSizeIterator + DoubleEndedIterator` over
+/// `&T` (see `Grid::row_iter`).
+pub struct IterRows<'a, T: Clone> {
+    grid: &'a Grid<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: Clone> Iterator for IterRows<'a, T> {
+    type Item = std::slice::Iter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let row = self.grid.row_iter(self.front);
+        self.front += 1;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterRows<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterRows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.grid.row_iter(self.back))
+    }
+}
+
+/// IterCols is an `ExactSizeIterator + DoubleEndedIterator` over the columns
+/// of a Grid, each itself an `ExactSizeIterator + DoubleEndedIterator` over
+/// `&T` (see `Grid::col_iter`).
+pub struct IterCols<'a, T: Clone> {
+    grid: &'a Grid<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T: Clone> Iterator for IterCols<'a, T> {
+    type Item = ColIter<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let col = self.grid.col_iter(self.front);
+        self.front += 1;
+        Some(col)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterCols<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterCols<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.grid.col_iter(self.back))
+    }
+}
+
+impl<'a, T: Clone> Grid<T> {
     /// Define a new grid of size XxY
-    pub fn new(x: usize, y: usize) -> Self {
+    pub fn new(x: usize, y: usize) -> Self
+    where
+        T: Default,
+    {
         Grid {
-            g: vec![vec![T::default(); x]; y],
+            g: vec![T::default(); x * y],
+            width: x,
+            height: y,
+        }
+    }
+
+    /// Define a new grid of size XxY, filling each cell by calling `f` with
+    /// that cell's `Location` in row-major order. Unlike `new` this doesn't
+    /// require `T: Default`, which is handy when every cell is computed
+    /// (checkerboards, distance fields, seeded noise) rather than parsed
+    /// from input.
+    pub fn with_generator(x: usize, y: usize, f: impl Fn(Location) -> T) -> Self {
+        let mut g = Vec::with_capacity(x * y);
+        for idx in 0..x * y {
+            g.push(f(Location((idx % x) as isize, (idx / x) as isize)));
+        }
+        Grid {
+            g,
             width: x,
             height: y,
         }
@@ -106,37 +271,146 @@ impl<'a, T: Default + Clone> Grid<T> {
 
     /// `iter` gives a reference iterator to a Grid<T>
     pub fn iter(&'a self) -> GridIter<'a, T> {
-        GridIter {
+        GridIter { grid: self, cur: 0 }
+    }
+
+    /// `iter_mut` gives a mutable reference iterator to a Grid<T>, yielding
+    /// `(Location, &mut T)` in the same row-major order as `iter`.
+    pub fn iter_mut(&'a mut self) -> GridIterMut<'a, T> {
+        GridIterMut {
+            width: self.width,
+            cur: 0,
+            iter: self.g.iter_mut(),
+        }
+    }
+
+    /// `row_iter` gives an `ExactSizeIterator + DoubleEndedIterator` over the
+    /// `&T` in row `y`, left to right. Panics if `y >= height`.
+    pub fn row_iter(&'a self, y: usize) -> std::slice::Iter<'a, T> {
+        assert!(y < self.height, "row_iter: y out of bounds");
+        let start = y * self.width;
+        self.g[start..start + self.width].iter()
+    }
+
+    /// `col_iter` gives an `ExactSizeIterator + DoubleEndedIterator` over the
+    /// `&T` in column `x`, top to bottom. Panics if `x >= width`.
+    pub fn col_iter(&'a self, x: usize) -> ColIter<'a, T> {
+        assert!(x < self.width, "col_iter: x out of bounds");
+        ColIter {
+            grid: self,
+            x,
+            front: 0,
+            back: self.height,
+        }
+    }
+
+    /// `iter_rows` gives an `ExactSizeIterator + DoubleEndedIterator` over
+    /// every row, each itself an `ExactSizeIterator + DoubleEndedIterator`
+    /// over that row's `&T` left to right (see `row_iter`).
+    pub fn iter_rows(&'a self) -> IterRows<'a, T> {
+        IterRows {
             grid: self,
-            cur: None,
+            front: 0,
+            back: self.height,
+        }
+    }
+
+    /// `iter_cols` gives an `ExactSizeIterator + DoubleEndedIterator` over
+    /// every column, each itself an `ExactSizeIterator + DoubleEndedIterator`
+    /// over that column's `&T` top to bottom (see `col_iter`).
+    pub fn iter_cols(&'a self) -> IterCols<'a, T> {
+        IterCols {
+            grid: self,
+            front: 0,
+            back: self.width,
         }
     }
 
     /// The grid width.
     /// NOTE: The grid is indexed from 0 so this is one past the last index.
     pub fn width(&self) -> usize {
-        self.g[0].len()
+        self.width
     }
 
     /// The grid height.
     /// NOTE: The grid is indexed from 0 so this is one past the last index.
     pub fn height(&self) -> usize {
-        self.g.len()
+        self.height
+    }
+
+    /// `location_to_index` converts a `Location` into the linear index used
+    /// by the backing `Vec<T>`. Panics the same way `get`/`get_mut`/`add` do
+    /// if the location isn't a valid index into this grid.
+    pub fn location_to_index(&self, l: &Location) -> usize {
+        assert!(self.in_bounds(l), "location {l} out of bounds");
+        (l.1 as usize) * self.width + (l.0 as usize)
+    }
+
+    /// `index_to_location` converts a linear index into the backing
+    /// `Vec<T>` back into its `Location`.
+    pub fn index_to_location(&self, idx: usize) -> Location {
+        Location((idx % self.width) as isize, (idx / self.width) as isize)
+    }
+
+    /// Return the T at the given linear index (see `location_to_index`).
+    pub fn get_index(&'a self, idx: usize) -> &'a T {
+        &self.g[idx]
+    }
+
+    /// Return the mutable T at the given linear index (see `location_to_index`).
+    pub fn get_index_mut(&'a mut self, idx: usize) -> &'a mut T {
+        &mut self.g[idx]
     }
 
     /// Replace the given Location with a new T
     pub fn add(&mut self, l: &Location, t: T) {
-        self.g[l.1 as usize][l.0 as usize] = t
+        let idx = self.location_to_index(l);
+        self.g[idx] = t
     }
 
     /// Return the T at the given Location
     pub fn get(&'a self, l: &Location) -> &'a T {
-        &self.g[l.1 as usize][l.0 as usize]
+        let idx = self.location_to_index(l);
+        &self.g[idx]
     }
 
     /// Return the mutable T at the given Location
     pub fn get_mut(&'a mut self, l: &Location) -> &'a mut T {
-        &mut self.g[l.1 as usize][l.0 as usize]
+        let idx = self.location_to_index(l);
+        &mut self.g[idx]
+    }
+
+    fn in_bounds(&self, l: &Location) -> bool {
+        l.0 >= 0 && l.1 >= 0 && l.0 < self.width as isize && l.1 < self.height as isize
+    }
+
+    /// Return the T at the given Location, or `None` if it is out of bounds
+    /// (a negative component, or at/beyond `width`/`height`).
+    pub fn try_get(&'a self, l: &Location) -> Option<&'a T> {
+        if !self.in_bounds(l) {
+            return None;
+        }
+        Some(self.get(l))
+    }
+
+    /// Return the mutable T at the given Location, or `None` if it is out of
+    /// bounds (a negative component, or at/beyond `width`/`height`).
+    pub fn try_get_mut(&'a mut self, l: &Location) -> Option<&'a mut T> {
+        if !self.in_bounds(l) {
+            return None;
+        }
+        Some(self.get_mut(l))
+    }
+
+    /// Replace the given Location with a new T, returning `false` instead of
+    /// panicking if the location is out of bounds (a negative component, or
+    /// at/beyond `width`/`height`).
+    pub fn try_add(&mut self, l: &Location, t: T) -> bool {
+        if !self.in_bounds(l) {
+            return false;
+        }
+        self.add(l, t);
+        true
     }
 
     fn neighbors_impl(&'a self, l: &Location, all: bool) -> Vec<(Location, &T)> {
@@ -151,11 +425,10 @@ impl<'a, T: Default + Clone> Grid<T> {
             tests.push((x - 1, y - 1));
         }
         for t in &tests {
-            if t.0 >= 0 && t.1 >= 0 && t.0 < self.g[0].len() as isize && t.1 < self.g.len() as isize
-            {
-                let x = t.0 as usize;
-                let y = t.1 as usize;
-                n.push((Location(t.0, t.1), &self.g[y][x]));
+            if t.0 >= 0 && t.1 >= 0 && t.0 < self.width as isize && t.1 < self.height as isize {
+                let loc = Location(t.0, t.1);
+                let idx = self.location_to_index(&loc);
+                n.push((loc, &self.g[idx]));
             }
         }
         n
@@ -170,11 +443,102 @@ impl<'a, T: Default + Clone> Grid<T> {
     pub fn neighbors_all(&'a self, l: &Location) -> Vec<(Location, &T)> {
         self.neighbors_impl(l, true)
     }
+
+    /// Copy the `width`x`height` window starting at `top_left` into a new
+    /// owned `Grid<T>`. Panics if the window extends beyond this grid's
+    /// bounds.
+    pub fn subgrid(&self, top_left: &Location, width: usize, height: usize) -> Grid<T> {
+        assert!(
+            top_left.0 >= 0 && top_left.1 >= 0,
+            "subgrid: top_left must be non-negative"
+        );
+        let ox = top_left.0 as usize;
+        let oy = top_left.1 as usize;
+        assert!(
+            ox + width <= self.width && oy + height <= self.height,
+            "subgrid: window exceeds grid bounds"
+        );
+        let mut g = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                g.push(self.get(&Location((ox + x) as isize, (oy + y) as isize)).clone());
+            }
+        }
+        Grid { g, width, height }
+    }
+
+    /// Build a new `Grid<T>` by converting every cell of `other` through
+    /// `From<U>`, e.g. turning a `Grid<char>` into a `Grid<u8>`.
+    pub fn from_grid<U: Clone>(other: &Grid<U>) -> Grid<T>
+    where
+        T: From<U>,
+    {
+        let g = other.g.iter().cloned().map(T::from).collect();
+        Grid {
+            g,
+            width: other.width,
+            height: other.height,
+        }
+    }
+
+    fn remapped(&self, new_width: usize, new_height: usize, src: impl Fn(usize, usize) -> Location) -> Grid<T> {
+        let mut g = Vec::with_capacity(new_width * new_height);
+        for y in 0..new_height {
+            for x in 0..new_width {
+                g.push(self.get(&src(x, y)).clone());
+            }
+        }
+        Grid {
+            g,
+            width: new_width,
+            height: new_height,
+        }
+    }
+
+    /// Rotate the grid 90 degrees clockwise, swapping width and height.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let h = self.height;
+        self.remapped(self.height, self.width, |x, y| {
+            Location(y as isize, (h - 1 - x) as isize)
+        })
+    }
+
+    /// Rotate the grid 90 degrees counter-clockwise, swapping width and height.
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let w = self.width;
+        self.remapped(self.height, self.width, |x, y| {
+            Location((w - 1 - y) as isize, x as isize)
+        })
+    }
+
+    /// Rotate the grid 180 degrees.
+    pub fn rotate_180(&self) -> Grid<T> {
+        let w = self.width;
+        let h = self.height;
+        self.remapped(w, h, |x, y| Location((w - 1 - x) as isize, (h - 1 - y) as isize))
+    }
+
+    /// Flip the grid left-to-right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let w = self.width;
+        self.remapped(w, self.height, |x, y| Location((w - 1 - x) as isize, y as isize))
+    }
+
+    /// Flip the grid top-to-bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let h = self.height;
+        self.remapped(self.width, h, |x, y| Location(x as isize, (h - 1 - y) as isize))
+    }
+
+    /// Transpose the grid along its main diagonal, swapping width and height.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remapped(self.height, self.width, |x, y| Location(y as isize, x as isize))
+    }
 }
 
 /// Given a grid<T> print it out. This is not part of the main impl as it does put
 /// additional constraints on T that may not be needed in all cases.
-pub fn print_grid<T: Default + Clone + std::fmt::Debug + std::fmt::Display>(grid: &Grid<T>) {
+pub fn print_grid<T: Clone + std::fmt::Debug + std::fmt::Display>(grid: &Grid<T>) {
     for g in grid {
         print!("{}", g.1);
         if usize::try_from(g.0 .0).unwrap() == grid.width() - 1 {
@@ -183,3 +547,279 @@ pub fn print_grid<T: Default + Clone + std::fmt::Debug + std::fmt::Display>(grid
     }
     println!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(width: usize, height: usize) -> Grid<i32> {
+        let mut g = Grid::new(width, height);
+        let mut v = 0;
+        for y in 0..height {
+            for x in 0..width {
+                g.add(&Location(x as isize, y as isize), v);
+                v += 1;
+            }
+        }
+        g
+    }
+
+    #[test]
+    fn iter_mut_mutates_cells_in_row_major_order() {
+        let mut g = filled(3, 2);
+        for (loc, cell) in g.iter_mut() {
+            *cell += loc.0 as i32 * 100;
+        }
+        assert_eq!(
+            g.iter().map(|(_, &v)| v).collect::<Vec<_>>(),
+            vec![0, 101, 202, 3, 104, 205]
+        );
+    }
+
+    #[test]
+    fn new_has_requested_dimensions_and_default_cells() {
+        let g = Grid::<i32>::new(3, 2);
+        assert_eq!(g.width(), 3);
+        assert_eq!(g.height(), 2);
+        assert_eq!(*g.get(&Location(2, 1)), 0);
+    }
+
+    #[test]
+    fn get_and_add_round_trip_through_the_flat_backing_store() {
+        let mut g = Grid::<i32>::new(3, 2);
+        g.add(&Location(2, 1), 42);
+        assert_eq!(*g.get(&Location(2, 1)), 42);
+        assert_eq!(*g.get(&Location(0, 0)), 0);
+    }
+
+    #[test]
+    fn try_get_returns_none_for_out_of_bounds_locations() {
+        let g = filled(3, 2);
+        assert_eq!(g.try_get(&Location(2, 1)), Some(&5));
+        assert_eq!(g.try_get(&Location(3, 0)), None);
+        assert_eq!(g.try_get(&Location(0, 2)), None);
+        assert_eq!(g.try_get(&Location(-1, 0)), None);
+        assert_eq!(g.try_get(&Location(0, -1)), None);
+    }
+
+    #[test]
+    fn try_get_mut_returns_none_for_out_of_bounds_locations() {
+        let mut g = filled(3, 2);
+        if let Some(cell) = g.try_get_mut(&Location(1, 1)) {
+            *cell = 99;
+        }
+        assert_eq!(*g.get(&Location(1, 1)), 99);
+        assert_eq!(g.try_get_mut(&Location(3, 0)), None);
+        assert_eq!(g.try_get_mut(&Location(-1, 0)), None);
+    }
+
+    #[test]
+    fn try_add_rejects_out_of_bounds_locations() {
+        let mut g = filled(3, 2);
+        assert!(g.try_add(&Location(1, 1), 99));
+        assert_eq!(*g.get(&Location(1, 1)), 99);
+        assert!(!g.try_add(&Location(3, 0), 1));
+        assert!(!g.try_add(&Location(0, 2), 1));
+        assert!(!g.try_add(&Location(-1, 0), 1));
+    }
+
+    #[test]
+    fn location_and_index_conversions_round_trip() {
+        let g = Grid::<i32>::new(3, 2);
+        for y in 0..2 {
+            for x in 0..3 {
+                let loc = Location(x, y);
+                let idx = g.location_to_index(&loc);
+                assert_eq!(g.index_to_location(idx), loc);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_yields_cells_in_row_major_order() {
+        let g = filled(3, 2);
+        let got: Vec<(Location, i32)> = g.iter().map(|(l, &v)| (l, v)).collect();
+        assert_eq!(
+            got,
+            vec![
+                (Location(0, 0), 0),
+                (Location(1, 0), 1),
+                (Location(2, 0), 2),
+                (Location(0, 1), 3),
+                (Location(1, 1), 4),
+                (Location(2, 1), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_excludes_diagonals_and_respects_edges() {
+        let g = filled(3, 3);
+        let mut got: Vec<Location> = g
+            .neighbors(&Location(0, 0))
+            .into_iter()
+            .map(|(l, _)| l)
+            .collect();
+        got.sort();
+        assert_eq!(got, vec![Location(1, 0), Location(0, 1)]);
+    }
+
+    #[test]
+    fn neighbors_all_includes_diagonals_and_respects_edges() {
+        let g = filled(3, 3);
+        let mut got: Vec<Location> = g
+            .neighbors_all(&Location(1, 1))
+            .into_iter()
+            .map(|(l, _)| l)
+            .collect();
+        got.sort();
+        let mut want = vec![
+            Location(0, 0),
+            Location(1, 0),
+            Location(2, 0),
+            Location(0, 1),
+            Location(2, 1),
+            Location(0, 2),
+            Location(1, 2),
+            Location(2, 2),
+        ];
+        want.sort();
+        assert_eq!(got, want);
+    }
+
+    fn rows_of(g: &Grid<i32>) -> Vec<Vec<i32>> {
+        g.iter_rows().map(|r| r.copied().collect()).collect()
+    }
+
+    #[test]
+    fn row_iter_and_col_iter_are_exact_size_and_double_ended() {
+        let g = filled(4, 3);
+
+        let row = g.row_iter(1);
+        assert_eq!(row.len(), 4);
+        assert_eq!(row.rev().copied().collect::<Vec<_>>(), vec![7, 6, 5, 4]);
+        assert_eq!(g.row_iter(1).nth_back(1), Some(&6));
+
+        let col = g.col_iter(2);
+        assert_eq!(col.len(), 3);
+        assert_eq!(col.rev().copied().collect::<Vec<_>>(), vec![10, 6, 2]);
+        assert_eq!(g.col_iter(2).nth_back(1), Some(&6));
+    }
+
+    #[test]
+    fn iter_rows_is_exact_size_and_double_ended() {
+        let g = filled(4, 3);
+        assert_eq!(g.iter_rows().len(), 3);
+
+        let reversed: Vec<Vec<i32>> = g.iter_rows().rev().map(|r| r.copied().collect()).collect();
+        assert_eq!(
+            reversed,
+            vec![vec![8, 9, 10, 11], vec![4, 5, 6, 7], vec![0, 1, 2, 3]]
+        );
+
+        let last_row: Vec<i32> = g.iter_rows().nth_back(0).unwrap().copied().collect();
+        assert_eq!(last_row, vec![8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn iter_cols_is_exact_size_and_double_ended() {
+        let g = filled(4, 3);
+        assert_eq!(g.iter_cols().len(), 4);
+
+        let reversed: Vec<Vec<i32>> = g.iter_cols().rev().map(|c| c.copied().collect()).collect();
+        assert_eq!(
+            reversed,
+            vec![
+                vec![3, 7, 11],
+                vec![2, 6, 10],
+                vec![1, 5, 9],
+                vec![0, 4, 8],
+            ]
+        );
+
+        let second_to_last_col: Vec<i32> = g.iter_cols().nth_back(1).unwrap().copied().collect();
+        assert_eq!(second_to_last_col, vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn subgrid_copies_the_requested_window() {
+        let g = filled(4, 3);
+        let s = g.subgrid(&Location(1, 1), 2, 2);
+        assert_eq!(s.width(), 2);
+        assert_eq!(s.height(), 2);
+        assert_eq!(rows_of(&s), vec![vec![5, 6], vec![9, 10]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "subgrid: window exceeds grid bounds")]
+    fn subgrid_panics_when_the_window_exceeds_bounds() {
+        let g = filled(4, 3);
+        g.subgrid(&Location(3, 0), 2, 1);
+    }
+
+    #[test]
+    fn from_grid_converts_every_cell_through_from() {
+        let g = filled(2, 2);
+        let converted: Grid<i64> = Grid::from_grid(&g);
+        assert_eq!(converted.width(), 2);
+        assert_eq!(converted.height(), 2);
+        assert_eq!(
+            converted.iter().map(|(_, &v)| v).collect::<Vec<_>>(),
+            vec![0i64, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn rotate_cw_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.rotate_cw();
+        assert_eq!(r.width(), 2);
+        assert_eq!(r.height(), 3);
+        assert_eq!(rows_of(&r), vec![vec![3, 0], vec![4, 1], vec![5, 2]]);
+    }
+
+    #[test]
+    fn rotate_ccw_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.rotate_ccw();
+        assert_eq!(r.width(), 2);
+        assert_eq!(r.height(), 3);
+        assert_eq!(rows_of(&r), vec![vec![2, 5], vec![1, 4], vec![0, 3]]);
+    }
+
+    #[test]
+    fn rotate_180_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.rotate_180();
+        assert_eq!(r.width(), 3);
+        assert_eq!(r.height(), 2);
+        assert_eq!(rows_of(&r), vec![vec![5, 4, 3], vec![2, 1, 0]]);
+    }
+
+    #[test]
+    fn flip_horizontal_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.flip_horizontal();
+        assert_eq!(r.width(), 3);
+        assert_eq!(r.height(), 2);
+        assert_eq!(rows_of(&r), vec![vec![2, 1, 0], vec![5, 4, 3]]);
+    }
+
+    #[test]
+    fn flip_vertical_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.flip_vertical();
+        assert_eq!(r.width(), 3);
+        assert_eq!(r.height(), 2);
+        assert_eq!(rows_of(&r), vec![vec![3, 4, 5], vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn transpose_remaps_a_non_square_grid() {
+        let g = filled(3, 2);
+        let r = g.transpose();
+        assert_eq!(r.width(), 2);
+        assert_eq!(r.height(), 3);
+        assert_eq!(rows_of(&r), vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+    }
+}